@@ -42,34 +42,34 @@ fn dt_from_timestamp(ts: u32) -> DateTime<Utc> {
 // Parse a signature creation time subpacket
 // Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.4
 named!(
-    signature_creation_time<Subpacket>,
+    signature_creation_time<SubpacketData>,
     map!(
         // 4-octet time field
         be_u32,
-        |date| Subpacket::SignatureCreationTime(dt_from_timestamp(date))
+        |date| SubpacketData::SignatureCreationTime(dt_from_timestamp(date))
     )
 );
 
 // Parse an issuer subpacket
 // Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.5
 #[rustfmt::skip]
-named!(issuer<Subpacket>, map!(
+named!(issuer<SubpacketData>, map!(
     map_res!(complete!(take!(8)), KeyId::from_slice),
-    Subpacket::Issuer
+    SubpacketData::Issuer
 ));
 
 // Parse a key expiration time subpacket
 // Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.6
 #[rustfmt::skip]
-named!(key_expiration<Subpacket>, map!(
+named!(key_expiration<SubpacketData>, map!(
     // 4-octet time field
     be_u32,
-    |date| Subpacket::KeyExpirationTime(dt_from_timestamp(date))
+    |date| SubpacketData::KeyExpirationTime(dt_from_timestamp(date))
 ));
 
 /// Parse a preferred symmetric algorithms subpacket
 /// Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.7
-fn pref_sym_alg(body: &[u8]) -> IResult<&[u8], Subpacket> {
+fn pref_sym_alg(body: &[u8]) -> IResult<&[u8], SubpacketData> {
     let list: SmallVec<[SymmetricKeyAlgorithm; 8]> = body
         .iter()
         .map(|v| {
@@ -81,12 +81,12 @@ fn pref_sym_alg(body: &[u8]) -> IResult<&[u8], Subpacket> {
             nom::Err::Error(nom::error::Error::new(body, nom::error::ErrorKind::MapOpt))
         })?;
 
-    Ok((&b""[..], Subpacket::PreferredSymmetricAlgorithms(list)))
+    Ok((&b""[..], SubpacketData::PreferredSymmetricAlgorithms(list)))
 }
 
 /// Parse a preferred hash algorithms subpacket
 /// Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.8
-fn pref_hash_alg(body: &[u8]) -> IResult<&[u8], Subpacket> {
+fn pref_hash_alg(body: &[u8]) -> IResult<&[u8], SubpacketData> {
     let list: SmallVec<[HashAlgorithm; 8]> = body
         .iter()
         .map(|v| HashAlgorithm::from_u8(*v).ok_or_else(|| format_err!("Invalid HashAlgorithm")))
@@ -95,12 +95,12 @@ fn pref_hash_alg(body: &[u8]) -> IResult<&[u8], Subpacket> {
             nom::Err::Error(nom::error::Error::new(body, nom::error::ErrorKind::MapOpt))
         })?;
 
-    Ok((&b""[..], Subpacket::PreferredHashAlgorithms(list)))
+    Ok((&b""[..], SubpacketData::PreferredHashAlgorithms(list)))
 }
 
 /// Parse a preferred compression algorithms subpacket
 /// Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.9
-fn pref_com_alg(body: &[u8]) -> IResult<&[u8], Subpacket> {
+fn pref_com_alg(body: &[u8]) -> IResult<&[u8], SubpacketData> {
     let list: SmallVec<[CompressionAlgorithm; 8]> = body
         .iter()
         .map(|v| {
@@ -112,23 +112,23 @@ fn pref_com_alg(body: &[u8]) -> IResult<&[u8], Subpacket> {
             nom::Err::Error(nom::error::Error::new(body, nom::error::ErrorKind::MapOpt))
         })?;
 
-    Ok((&b""[..], Subpacket::PreferredCompressionAlgorithms(list)))
+    Ok((&b""[..], SubpacketData::PreferredCompressionAlgorithms(list)))
 }
 
 // Parse a signature expiration time subpacket
 // Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.10
 #[rustfmt::skip]
-named!(signature_expiration_time<Subpacket>, map!(
+named!(signature_expiration_time<SubpacketData>, map!(
     // 4-octet time field
     be_u32,
-    |date| Subpacket::SignatureExpirationTime(dt_from_timestamp(date))
+    |date| SubpacketData::SignatureExpirationTime(dt_from_timestamp(date))
 ));
 
 // Parse a exportable certification subpacket.
 // Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.11
 named!(
-    exportable_certification<Subpacket>,
-    map!(complete!(be_u8), |v| Subpacket::ExportableCertification(
+    exportable_certification<SubpacketData>,
+    map!(complete!(be_u8), |v| SubpacketData::ExportableCertification(
         v == 1
     ))
 );
@@ -136,45 +136,53 @@ named!(
 // Parse a revocable subpacket
 // Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.12
 named!(
-    revocable<Subpacket>,
-    map!(complete!(be_u8), |v| Subpacket::Revocable(v == 1))
+    revocable<SubpacketData>,
+    map!(complete!(be_u8), |v| SubpacketData::Revocable(v == 1))
 );
 
 // Parse a trust signature subpacket.
 // Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.13
 #[rustfmt::skip]
-named!(trust_signature<Subpacket>, do_parse!(
+named!(trust_signature<SubpacketData>, do_parse!(
        depth: be_u8
     >> value: be_u8
-    >> (Subpacket::TrustSignature(depth, value))
+    >> (SubpacketData::TrustSignature(depth, value))
 ));
 
 // Parse a regular expression subpacket.
 // Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.14
 #[rustfmt::skip]
-named!(regular_expression<Subpacket>, map!(
-    map!(rest, read_string), Subpacket::RegularExpression
+named!(regular_expression<SubpacketData>, map!(
+    map!(rest, read_string), SubpacketData::RegularExpression
 ));
 
 // Parse a revocation key subpacket
 // Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.15
-#[rustfmt::skip]
-named!(revocation_key<Subpacket>, do_parse!(
-             class: map_opt!(be_u8, RevocationKeyClass::from_u8)
-    >>   algorithm: map_opt!(be_u8, PublicKeyAlgorithm::from_u8)
-    // TODO: V5 Keys have 32 octets here
-    >>          fp: take!(20)
-    >> (Subpacket::RevocationKey(RevocationKey::new(
-        class,
-        algorithm,
-        fp,
-    )))
-));
+fn revocation_key(body: &[u8]) -> IResult<&[u8], SubpacketData> {
+    let (body, class) = map_opt(be_u8, RevocationKeyClass::from_u8)(body)?;
+    let (body, algorithm) = map_opt(be_u8, PublicKeyAlgorithm::from_u8)(body)?;
+    // Nothing follows the fingerprint in this subpacket, so take whatever remains: that
+    // naturally handles a 20-octet V4/SHA-1 fingerprint or a 32-octet V5/SHA-256 one. But the
+    // body is still fixed-size overall (22 or 34 octets), so reject anything else outright
+    // rather than accepting an arbitrary-length fingerprint.
+    let (body, fp) = rest(body)?;
+    if fp.len() != 20 && fp.len() != 32 {
+        return Err(nom::Err::Error(nom::error::Error::new(
+            body,
+            nom::error::ErrorKind::LengthValue,
+        )));
+    }
+
+    Ok((
+        body,
+        SubpacketData::RevocationKey(RevocationKey::new(class, algorithm, fp)),
+    ))
+}
 
 // Parse a notation data subpacket
 // Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.16
 #[rustfmt::skip]
-named!(notation_data<Subpacket>, do_parse!(
+named!(notation_data<SubpacketData>, do_parse!(
                   // Flags
         readable: map!(be_u8, |v| v == 0x80)
     >>            tag!(&[0, 0, 0])
@@ -182,94 +190,117 @@ named!(notation_data<Subpacket>, do_parse!(
     >> value_len: be_u16
     >>      name: map!(take!(name_len), read_string)
     >>     value: map!(take!(value_len), read_string)
-    >> (Subpacket::Notation(Notation { readable, name, value }))
+    >> (SubpacketData::Notation(Notation { readable, name, value }))
 ));
 
 /// Parse a key server preferences subpacket
 /// https://tools.ietf.org/html/rfc4880.html#section-5.2.3.17
-fn key_server_prefs(body: &[u8]) -> IResult<&[u8], Subpacket> {
+fn key_server_prefs(body: &[u8]) -> IResult<&[u8], SubpacketData> {
     Ok((
         &b""[..],
-        Subpacket::KeyServerPreferences(SmallVec::from_slice(body)),
+        SubpacketData::KeyServerPreferences(KeyServerPreferences::from_slice(body)),
     ))
 }
 
 // Parse a preferred key server subpacket
 // Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.18
 #[rustfmt::skip]
-named!(preferred_key_server<Subpacket>,do_parse!(
+named!(preferred_key_server<SubpacketData>,do_parse!(
        body: map_res!(rest, str::from_utf8)
-    >> ({ Subpacket::PreferredKeyServer(body.to_string()) })
+    >> ({ SubpacketData::PreferredKeyServer(body.to_string()) })
 ));
 
 // Parse a primary user id subpacket
 // Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.19
 named!(
-    primary_userid<Subpacket>,
-    map!(be_u8, |a| Subpacket::IsPrimary(a == 1))
+    primary_userid<SubpacketData>,
+    map!(be_u8, |a| SubpacketData::IsPrimary(a == 1))
 );
 
 // Parse a policy URI subpacket.
 // Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.20
 #[rustfmt::skip]
-named!(policy_uri<Subpacket>, map!(
-    map!(rest, read_string), Subpacket::PolicyURI
+named!(policy_uri<SubpacketData>, map!(
+    map!(rest, read_string), SubpacketData::PolicyURI
 ));
 
 /// Parse a key flags subpacket
 /// Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.21
-fn key_flags(body: &[u8]) -> IResult<&[u8], Subpacket> {
-    Ok((&b""[..], Subpacket::KeyFlags(SmallVec::from_slice(body))))
+fn key_flags(body: &[u8]) -> IResult<&[u8], SubpacketData> {
+    Ok((&b""[..], SubpacketData::KeyFlags(KeyFlags::from_slice(body))))
 }
 
 // Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.22
 #[rustfmt::skip]
-named!(signers_userid<Subpacket>, do_parse!(
+named!(signers_userid<SubpacketData>, do_parse!(
        body: map_res!(rest, str::from_utf8)
-    >> (Subpacket::SignersUserID(body.to_string())))
+    >> (SubpacketData::SignersUserID(body.to_string())))
 );
 
 /// Parse a features subpacket
 /// Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.24
-fn features(body: &[u8]) -> IResult<&[u8], Subpacket> {
-    Ok((&b""[..], Subpacket::Features(SmallVec::from_slice(body))))
+fn features(body: &[u8]) -> IResult<&[u8], SubpacketData> {
+    Ok((&b""[..], SubpacketData::Features(Features::from_slice(body))))
 }
 
 // Parse a revocation reason subpacket
 // Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.23
 #[rustfmt::skip]
-named!(rev_reason<Subpacket>, do_parse!(
+named!(rev_reason<SubpacketData>, do_parse!(
          code: map_opt!(be_u8, RevocationCode::from_u8)
     >> reason: map!(rest, read_string)
-    >> (Subpacket::RevocationReason(code, reason))
+    >> (SubpacketData::RevocationReason(code, reason))
 ));
 
 // Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.25
 #[rustfmt::skip]
-named!(sig_target<Subpacket>, do_parse!(
+named!(sig_target<SubpacketData>, do_parse!(
         pub_alg: map_opt!(be_u8, PublicKeyAlgorithm::from_u8)
     >> hash_alg: map_opt!(be_u8, HashAlgorithm::from_u8)
     >>     hash: rest
-    >> (Subpacket::SignatureTarget(pub_alg, hash_alg, hash.to_vec()))
+    >> (SubpacketData::SignatureTarget(pub_alg, hash_alg, hash.to_vec()))
 ));
 
 // Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.26
 #[rustfmt::skip]
-named!(embedded_sig<Subpacket>, map!(call!(parse, Version::New), |sig| {
-    Subpacket::EmbeddedSignature(Box::new(sig))
+named!(embedded_sig<SubpacketData>, map!(call!(parse, Version::New), |sig| {
+    SubpacketData::EmbeddedSignature(Box::new(sig))
 }));
 
-// Parse an issuer subpacket
+// Parse an issuer fingerprint subpacket
 // Ref: https://tools.ietf.org/html/draft-ietf-openpgp-rfc4880bis-05#section-5.2.3.28
-#[rustfmt::skip]
-named!(issuer_fingerprint<Subpacket>, do_parse!(
-           version: map_opt!(be_u8, KeyVersion::from_u8)
-    >> fingerprint: rest
-    >> (Subpacket::IssuerFingerprint(version, SmallVec::from_slice(fingerprint)))
-));
+fn issuer_fingerprint(body: &[u8]) -> IResult<&[u8], SubpacketData> {
+    let (body, version) = map_opt(be_u8, KeyVersion::from_u8)(body)?;
+    // The fingerprint length is fixed by the key version it was computed under: 20 octets for
+    // a V4/SHA-1 fingerprint, 32 for a V5/SHA-256 one. Require the body to be exactly that
+    // long, rather than just taking a prefix of it, so a too-long (or too-short) body is a
+    // parse error instead of silently dropping or short-reading trailing bytes.
+    let fingerprint_len = match version {
+        KeyVersion::V4 => 20,
+        KeyVersion::V5 => 32,
+        _ => {
+            return Err(nom::Err::Error(nom::error::Error::new(
+                body,
+                nom::error::ErrorKind::MapOpt,
+            )))
+        }
+    };
+    if body.len() != fingerprint_len {
+        return Err(nom::Err::Error(nom::error::Error::new(
+            body,
+            nom::error::ErrorKind::LengthValue,
+        )));
+    }
+    let (body, fingerprint) = take(fingerprint_len)(body)?;
+
+    Ok((
+        body,
+        SubpacketData::IssuerFingerprint(version, SmallVec::from_slice(fingerprint)),
+    ))
+}
 
 /// Parse a preferred aead subpacket
-fn pref_aead_alg(body: &[u8]) -> IResult<&[u8], Subpacket> {
+fn pref_aead_alg(body: &[u8]) -> IResult<&[u8], SubpacketData> {
     let list: SmallVec<[AeadAlgorithm; 2]> = body
         .iter()
         .map(|v| AeadAlgorithm::from_u8(*v).ok_or_else(|| format_err!("Invalid AeadAlgorithm")))
@@ -278,10 +309,10 @@ fn pref_aead_alg(body: &[u8]) -> IResult<&[u8], Subpacket> {
             nom::Err::Error(nom::error::Error::new(body, nom::error::ErrorKind::MapOpt))
         })?;
 
-    Ok((&b""[..], Subpacket::PreferredAeadAlgorithms(list)))
+    Ok((&b""[..], SubpacketData::PreferredAeadAlgorithms(list)))
 }
 
-fn subpacket(typ: SubpacketType, body: &[u8]) -> IResult<&[u8], Subpacket> {
+fn subpacket_data(typ: SubpacketType, body: &[u8]) -> IResult<&[u8], SubpacketData> {
     use self::SubpacketType::*;
     debug!("parsing subpacket: {:?} {}", typ, hex::encode(body));
 
@@ -311,8 +342,8 @@ fn subpacket(typ: SubpacketType, body: &[u8]) -> IResult<&[u8], Subpacket> {
         EmbeddedSignature => embedded_sig(body),
         IssuerFingerprint => issuer_fingerprint(body),
         PreferredAead => pref_aead_alg(body),
-        Experimental(n) => Ok((body, Subpacket::Experimental(n, SmallVec::from_slice(body)))),
-        Other(n) => Ok((body, Subpacket::Other(n, body.to_vec()))),
+        Experimental(n) => Ok((body, SubpacketData::Experimental(n, SmallVec::from_slice(body)))),
+        Other(n) => Ok((body, SubpacketData::Other(n, body.to_vec()))),
     };
 
     if res.is_err() {
@@ -322,19 +353,105 @@ fn subpacket(typ: SubpacketType, body: &[u8]) -> IResult<&[u8], Subpacket> {
     res
 }
 
+/// Parses a single subpacket body, given its (already demasked) type and critical bit.
+///
+/// This only decodes the body and records the critical bit (0x80 on the type octet, RFC4880
+/// §5.2.3.1) on the result; it does not reject anything. A critical subpacket that fails to
+/// parse is turned into a hard error by `subpackets`, and one that falls back to
+/// `Other`/`Experimental` is rejected by `reject_unknown_critical`.
+fn subpacket(typ: SubpacketType, critical: bool, body: &[u8]) -> IResult<&[u8], Subpacket> {
+    let (rest, data) = subpacket_data(typ, body)?;
+
+    Ok((rest, Subpacket { critical, data }))
+}
+
 fn subpackets(input: &[u8]) -> IResult<&[u8], Vec<Subpacket>> {
     let (input, packets) = many0(nom::combinator::complete(|input| {
-        // the subpacket length (1, 2, or 5 octets)
+        // the subpacket length (1, 2, or 5 octets); it includes the type octet that follows,
+        // so it must be at least 1. This is length framing, not subpacket content, so a bogus
+        // value here (e.g. 0 from a truncated/corrupt header) is a hard parse error rather than
+        // something the non-critical fallback below papers over.
         let (input, len) = packet_length(input)?;
-        // the subpacket type (1 octet)
-        let (input, typ) = map_opt(be_u8, SubpacketType::from_u8)(input)?;
-        let (input, p) = map_parser(take(len - 1), |b| subpacket(typ, b))(input)?;
+        let body_len = len.checked_sub(1).ok_or_else(|| {
+            // `Err::Failure`, not `Err::Error`: this loop runs under `many0`, which treats
+            // `Err::Error` as "no more items" and swallows it, rewinding to before this
+            // attempt. That would turn a bogus zero-length header into a silently truncated
+            // subpacket list instead of the hard parse error it's meant to be.
+            nom::Err::Failure(nom::error::Error::new(input, nom::error::ErrorKind::LengthValue))
+        })?;
+        // the subpacket type (1 octet): the high bit marks the subpacket critical, the
+        // remaining 7 bits are the actual `SubpacketType` (RFC4880 §5.2.3.1).
+        let (input, type_octet) = be_u8(input)?;
+        let critical = type_octet & 0x80 != 0;
+        let raw_typ = type_octet & 0x7f;
+        let typ = SubpacketType::from_u8(raw_typ);
+        // The declared length is also framing, not content: if it claims more bytes than are
+        // actually left in this hashed/unhashed buffer, that's a corrupt header, not a
+        // malformed-but-recoverable subpacket body. Promote to `Err::Failure` unconditionally
+        // (regardless of `critical`) so `many0` hard-fails instead of silently dropping this
+        // subpacket and everything after it.
+        let (input, body) = take(body_len)(input).map_err(|_| {
+            nom::Err::Failure(nom::error::Error::new(input, nom::error::ErrorKind::Eof))
+        })?;
+
+        let p = match subpacket(typ, critical, body) {
+            Ok((_, p)) => p,
+            // The length framing was well-formed (we already sliced out exactly `len - 1`
+            // bytes above), so a non-critical subpacket that fails to parse is kept around as
+            // raw bytes under its original type tag instead of failing the whole signature.
+            // This matches real-world keyrings that carry slightly-broken subpackets and lets
+            // the rest of the signature (and the MPIs that follow it) still be recovered and
+            // re-serialized. A critical subpacket in the same situation must still fail: see
+            // `subpacket`/`reject_unknown_critical`.
+            Err(_) if !critical => Subpacket {
+                critical,
+                data: SubpacketData::Other(raw_typ, body.to_vec()),
+            },
+            // Promote to `Err::Failure`, discarding the specific inner error (whether it was
+            // `Error` or, for a body too short for its fields, `Incomplete`): this loop runs
+            // under `many0`, which treats anything but `Failure` as "no more items" and would
+            // otherwise drop the critical subpacket - and everything after it in this
+            // hashed/unhashed set - instead of invalidating the signature as RFC4880 §5.2.3.1
+            // requires.
+            Err(_) => {
+                return Err(nom::Err::Failure(nom::error::Error::new(
+                    body,
+                    nom::error::ErrorKind::Verify,
+                )))
+            }
+        };
+
         Ok((input, p))
     }))(input)?;
 
     Ok((input, packets))
 }
 
+/// Rejects a signature that carries a critical subpacket this implementation does not
+/// understand (`Other`/`Experimental`). Per RFC4880 §5.2.3.1 a receiver MUST treat such a
+/// signature as invalid rather than silently ignoring the subpacket.
+fn reject_unknown_critical<'a>(
+    input: &'a [u8],
+    subpackets: &[Subpacket],
+) -> IResult<&'a [u8], ()> {
+    let has_unknown_critical = subpackets.iter().any(|sp| {
+        sp.critical
+            && matches!(
+                sp.data,
+                SubpacketData::Other(..) | SubpacketData::Experimental(..)
+            )
+    });
+
+    if has_unknown_critical {
+        return Err(nom::Err::Error(nom::error::Error::new(
+            input,
+            nom::error::ErrorKind::Verify,
+        )));
+    }
+
+    Ok((input, ()))
+}
+
 fn actual_signature<'a>(
     input: &'a [u8],
     typ: &PublicKeyAlgorithm,
@@ -413,7 +530,7 @@ fn v3_parser(
     Ok((input, s))
 }
 
-// Parse a v4 or v5 signature packet
+// Parse a v4 signature packet
 // Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3
 fn v4_parser(
     input: &[u8],
@@ -434,6 +551,9 @@ fn v4_parser(
     let (input, usub_len) = be_u16(input)?;
     // Unhashed subpacket data set (zero or more subpackets).
     let (input, usub) = map_parser(take(usub_len), subpackets)(input)?;
+    // A critical subpacket we don't recognize invalidates the whole signature.
+    let (input, ()) = reject_unknown_critical(input, &hsub)?;
+    let (input, ()) = reject_unknown_critical(input, &usub)?;
     // Two-octet field holding the left 16 bits of the signed hash value.
     let (input, ls_hash) = take(2u8)(input)?;
     // One or more multiprecision integers comprising the signature.
@@ -453,6 +573,63 @@ fn v4_parser(
     Ok((input, s))
 }
 
+// One-octet count of salt octets, followed by the salt itself. V5 signatures hash a random
+// salt alongside the signed data, a field V4 signatures don't have.
+fn salt(input: &[u8]) -> IResult<&[u8], Vec<u8>> {
+    let (input, salt_len) = be_u8(input)?;
+    let (input, salt) = take(salt_len)(input)?;
+
+    Ok((input, salt.to_vec()))
+}
+
+// Parse a v5 signature packet
+// Ref: https://www.ietf.org/archive/id/draft-ietf-openpgp-crypto-refresh-08.html#section-5.2.3
+fn v5_parser(
+    input: &[u8],
+    packet_version: Version,
+    version: SignatureVersion,
+) -> nom::IResult<&[u8], Signature> {
+    // One-octet signature type.
+    let (input, typ) = map_opt(be_u8, SignatureType::from_u8)(input)?;
+    // One-octet public-key algorithm.
+    let (input, pub_alg) = map_opt(be_u8, PublicKeyAlgorithm::from_u8)(input)?;
+    // One-octet hash algorithm.
+    let (input, hash_alg) = map_opt(be_u8, HashAlgorithm::from_u8)(input)?;
+    // Four-octet scalar octet count for following hashed subpacket data. V5 widened V4's
+    // two-octet count to a four-octet one, alongside the salt field further below.
+    let (input, hsub_len) = be_u32(input)?;
+    // Hashed subpacket data set (zero or more subpackets).
+    let (input, hsub) = map_parser(take(hsub_len), subpackets)(input)?;
+    // Four-octet scalar octet count for the following unhashed subpacket data.
+    let (input, usub_len) = be_u32(input)?;
+    // Unhashed subpacket data set (zero or more subpackets).
+    let (input, usub) = map_parser(take(usub_len), subpackets)(input)?;
+    // A critical subpacket we don't recognize invalidates the whole signature.
+    let (input, ()) = reject_unknown_critical(input, &hsub)?;
+    let (input, ()) = reject_unknown_critical(input, &usub)?;
+    // Two-octet field holding the left 16 bits of the signed hash value.
+    let (input, ls_hash) = take(2u8)(input)?;
+    // One-octet count of salt octets, followed by the salt itself.
+    let (input, sig_salt) = salt(input)?;
+    // One or more multiprecision integers comprising the signature.
+    let (input, sig) = actual_signature(input, &pub_alg)?;
+
+    let mut s = Signature::new(
+        packet_version,
+        version,
+        typ,
+        pub_alg,
+        hash_alg,
+        clone_into_array(ls_hash),
+        sig,
+        hsub,
+        usub,
+    );
+    s.config.salt = Some(sig_salt);
+
+    Ok((input, s))
+}
+
 // Parse a signature packet (Tag 2)
 // Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2
 pub fn parse(i: &[u8], packet_version: Version) -> IResult<&[u8], Signature> {
@@ -461,7 +638,7 @@ pub fn parse(i: &[u8], packet_version: Version) -> IResult<&[u8], Signature> {
         SignatureVersion::V2 => v3_parser(i, packet_version, version)?,
         SignatureVersion::V3 => v3_parser(i, packet_version, version)?,
         SignatureVersion::V4 => v4_parser(i, packet_version, version)?,
-        SignatureVersion::V5 => v4_parser(i, packet_version, version)?,
+        SignatureVersion::V5 => v5_parser(i, packet_version, version)?,
     };
     Ok((i, signature))
 }
@@ -476,7 +653,7 @@ mod tests {
         let (_, res) = pref_sym_alg(input.as_slice()).unwrap();
         assert_eq!(
             res,
-            Subpacket::PreferredSymmetricAlgorithms(
+            SubpacketData::PreferredSymmetricAlgorithms(
                 input
                     .iter()
                     .map(|i| SymmetricKeyAlgorithm::from_u8(*i).unwrap())
@@ -484,4 +661,215 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn test_reject_unknown_critical_subpacket() {
+        let sp = Subpacket {
+            critical: true,
+            data: SubpacketData::Other(120, vec![1, 2, 3]),
+        };
+        assert!(reject_unknown_critical(&b""[..], &[sp]).is_err());
+    }
+
+    #[test]
+    fn test_allow_unknown_noncritical_subpacket() {
+        let sp = Subpacket {
+            critical: false,
+            data: SubpacketData::Other(120, vec![1, 2, 3]),
+        };
+        assert!(reject_unknown_critical(&b""[..], &[sp]).is_ok());
+    }
+
+    #[test]
+    fn test_subpackets_non_critical_fallback_to_other() {
+        // A malformed, non-critical `ExportableCertification` (type 4, empty body, so
+        // `complete!(be_u8)` fails) followed by a well-formed, non-critical `Revocable` (type 7).
+        let input = [1u8, 4, 2, 7, 1];
+        let (rest, packets) = subpackets(&input).unwrap();
+
+        assert!(rest.is_empty());
+        assert_eq!(packets.len(), 2);
+        assert!(!packets[0].critical);
+        assert_eq!(packets[0].data, SubpacketData::Other(4, vec![]));
+        assert_eq!(packets[1].data, SubpacketData::Revocable(true));
+    }
+
+    #[test]
+    fn test_subpackets_rejects_zero_length_header() {
+        // A subpacket length of 0 doesn't even leave room for the type octet it's supposed to
+        // include: this is a framing error, not a malformed-but-recoverable subpacket.
+        assert!(matches!(subpackets(&[0u8]), Err(nom::Err::Failure(_))));
+    }
+
+    #[test]
+    fn test_v4_parser_rejects_zero_length_subpacket_header() {
+        // A zero-length subpacket header inside the hashed subpacket set must invalidate the
+        // whole signature rather than being silently dropped along with the bytes after it.
+        let body = [
+            0x00, // typ: Binary
+            0x01, // pub_alg: RSA
+            0x08, // hash_alg: SHA256
+            0x00, 0x01, // hsub_len: 1
+            0x00, // a single, bogus zero-length subpacket header
+            0x00, 0x00, // usub_len: 0
+            0xab, 0xcd, // ls_hash
+            0x00, 0x01, 0x01, // mpi: 1-bit value 0x01
+        ];
+
+        assert!(v4_parser(&body, Version::New, SignatureVersion::V4).is_err());
+    }
+
+    #[test]
+    fn test_subpackets_rejects_length_overrunning_remaining_input() {
+        // A length octet of 10 claims a 9-byte body, but only 2 bytes are actually left. This
+        // is a framing error (the declared length lies about the buffer), not a
+        // malformed-but-recoverable subpacket body, so it must hard-fail regardless of the
+        // critical bit rather than `many0` silently stopping and dropping these bytes.
+        let non_critical = [10u8, 0x05, 0xaa, 0xbb];
+        assert!(matches!(
+            subpackets(&non_critical),
+            Err(nom::Err::Failure(_))
+        ));
+
+        let critical = [10u8, 0x85, 0xaa, 0xbb];
+        assert!(matches!(subpackets(&critical), Err(nom::Err::Failure(_))));
+    }
+
+    #[test]
+    fn test_v4_parser_rejects_unparseable_critical_subpacket() {
+        // A critical `TrustSignature` (type 5) with a 1-byte body (it needs 2) must invalidate
+        // the whole signature instead of being dropped, along with any subpackets after it.
+        let body = [
+            0x00, // typ: Binary
+            0x01, // pub_alg: RSA
+            0x08, // hash_alg: SHA256
+            0x00, 0x09, // hsub_len: 9
+            0x05, 0x02, 0x00, 0x00, 0x00, 0x01, // hashed: SignatureCreationTime(1)
+            0x02, 0x85, 0xff, // hashed: critical TrustSignature, 1-byte body (needs 2)
+            0x00, 0x00, // usub_len: 0
+            0xab, 0xcd, // ls_hash
+            0x00, 0x01, 0x01, // mpi: 1-bit value 0x01
+        ];
+
+        assert!(v4_parser(&body, Version::New, SignatureVersion::V4).is_err());
+    }
+
+    #[test]
+    fn test_v4_parser_rejects_subpacket_length_overrunning_buffer() {
+        // A subpacket length that claims more bytes than remain in the hashed subpacket set is
+        // a framing error and must invalidate the whole signature, both when the offending
+        // subpacket is critical and when it isn't - `many0` must not be allowed to just stop
+        // and drop the claimed-but-missing bytes.
+        let make_body = |type_octet: u8| {
+            [
+                0x00, // typ: Binary
+                0x01, // pub_alg: RSA
+                0x08, // hash_alg: SHA256
+                0x00, 0x04, // hsub_len: 4
+                10, type_octet, 0xaa, 0xbb, // subpacket claims a 9-byte body, only 2 remain
+                0x00, 0x00, // usub_len: 0
+                0xab, 0xcd, // ls_hash
+                0x00, 0x01, 0x01, // mpi: 1-bit value 0x01
+            ]
+        };
+
+        // non-critical TrustSignature (type 5)
+        assert!(v4_parser(&make_body(0x05), Version::New, SignatureVersion::V4).is_err());
+        // critical TrustSignature (type 5 | 0x80)
+        assert!(v4_parser(&make_body(0x85), Version::New, SignatureVersion::V4).is_err());
+    }
+
+    #[test]
+    fn test_revocation_key_accepts_v4_and_v5_fingerprint_lengths() {
+        for fp_len in [20, 32] {
+            let mut body = vec![0x80, 1]; // class, RSA
+            body.extend(std::iter::repeat(0xab).take(fp_len));
+
+            let (rest, data) = revocation_key(&body).unwrap();
+            assert!(rest.is_empty());
+            assert_eq!(
+                data,
+                SubpacketData::RevocationKey(RevocationKey::new(
+                    RevocationKeyClass::from_u8(0x80).unwrap(),
+                    PublicKeyAlgorithm::RSA,
+                    &body[2..],
+                ))
+            );
+        }
+
+        // Two fingerprints that agree on their first 20 octets and only differ after must parse
+        // to distinct `RevocationKey`s -- this catches a `RevocationKey` that silently truncates
+        // the stored fingerprint down to the old V4/SHA-1 length instead of keeping the full
+        // V5/SHA-256 one.
+        let mut body_a = vec![0x80, 1];
+        body_a.extend(std::iter::repeat(0xab).take(20));
+        body_a.extend(std::iter::repeat(0xcd).take(12));
+
+        let mut body_b = vec![0x80, 1];
+        body_b.extend(std::iter::repeat(0xab).take(20));
+        body_b.extend(std::iter::repeat(0xef).take(12));
+
+        let (_, data_a) = revocation_key(&body_a).unwrap();
+        let (_, data_b) = revocation_key(&body_b).unwrap();
+        assert_ne!(data_a, data_b);
+    }
+
+    #[test]
+    fn test_revocation_key_rejects_off_length_fingerprint() {
+        let mut body = vec![0x80, 1]; // class, RSA
+        body.extend(std::iter::repeat(0xab).take(21));
+
+        assert!(revocation_key(&body).is_err());
+    }
+
+    #[test]
+    fn test_issuer_fingerprint_requires_exact_length() {
+        let mut v4_body = vec![4]; // KeyVersion::V4
+        v4_body.extend(std::iter::repeat(0xcd).take(20));
+        let (rest, data) = issuer_fingerprint(&v4_body).unwrap();
+        assert!(rest.is_empty());
+        assert!(matches!(data, SubpacketData::IssuerFingerprint(_, _)));
+
+        let mut v4_body_too_long = vec![4];
+        v4_body_too_long.extend(std::iter::repeat(0xcd).take(21));
+        assert!(issuer_fingerprint(&v4_body_too_long).is_err());
+
+        let mut v5_body = vec![5]; // KeyVersion::V5
+        v5_body.extend(std::iter::repeat(0xcd).take(32));
+        let (rest, _) = issuer_fingerprint(&v5_body).unwrap();
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn test_v5_salt_round_trip() {
+        let input = [3u8, 0xde, 0xad, 0xbe, 0xff, 0xff];
+        let (rest, parsed_salt) = salt(&input).unwrap();
+        assert_eq!(parsed_salt, vec![0xde, 0xad, 0xbe]);
+        assert_eq!(rest, &[0xff, 0xff]);
+    }
+
+    #[test]
+    fn test_v5_parser_reads_four_octet_subpacket_counts() {
+        let body = [
+            0x00, // typ: Binary
+            0x01, // pub_alg: RSA
+            0x08, // hash_alg: SHA256
+            0x00, 0x00, 0x00, 0x06, // hsub_len: 6 (be_u32, not be_u16)
+            0x05, 0x02, 0x00, 0x00, 0x00, 0x01, // hashed subpacket: SignatureCreationTime(1)
+            0x00, 0x00, 0x00, 0x00, // usub_len: 0 (be_u32)
+            0xab, 0xcd, // ls_hash
+            0x01, 0x42, // salt: 1-octet salt, value 0x42
+            0x00, 0x01, 0x01, // mpi: 1-bit value 0x01
+        ];
+
+        let (rest, sig) = v5_parser(&body, Version::New, SignatureVersion::V5).unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(sig.hashed_subpackets.len(), 1);
+        assert!(matches!(
+            sig.hashed_subpackets[0].data,
+            SubpacketData::SignatureCreationTime(_)
+        ));
+        assert!(sig.unhashed_subpackets.is_empty());
+        assert_eq!(sig.config.salt, Some(vec![0x42]));
+    }
 }
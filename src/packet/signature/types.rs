@@ -0,0 +1,435 @@
+use chrono::{DateTime, Utc};
+use smallvec::SmallVec;
+
+use crate::crypto::aead::AeadAlgorithm;
+use crate::crypto::hash::HashAlgorithm;
+use crate::crypto::public_key::PublicKeyAlgorithm;
+use crate::crypto::sym::SymmetricKeyAlgorithm;
+use crate::types::{CompressionAlgorithm, KeyId, KeyVersion, Mpi, RevocationKey, Version};
+
+/// The signature packet version.
+/// Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SignatureVersion {
+    V2,
+    V3,
+    V4,
+    V5,
+}
+
+impl SignatureVersion {
+    pub fn from_u8(n: u8) -> Option<Self> {
+        match n {
+            2 => Some(SignatureVersion::V2),
+            3 => Some(SignatureVersion::V3),
+            4 => Some(SignatureVersion::V4),
+            5 => Some(SignatureVersion::V5),
+            _ => None,
+        }
+    }
+}
+
+/// The type of a signature, as found in the one-octet signature type field.
+/// Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.1
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SignatureType {
+    Binary,
+    Text,
+    Standalone,
+    CertGeneric,
+    CertPersona,
+    CertCasual,
+    CertPositive,
+    SubkeyBinding,
+    KeyBinding,
+    Key,
+    KeyRevocation,
+    SubkeyRevocation,
+    CertRevocation,
+    Timestamp,
+    ThirdParty,
+}
+
+impl SignatureType {
+    pub fn from_u8(n: u8) -> Option<Self> {
+        use SignatureType::*;
+        Some(match n {
+            0x00 => Binary,
+            0x01 => Text,
+            0x02 => Standalone,
+            0x10 => CertGeneric,
+            0x11 => CertPersona,
+            0x12 => CertCasual,
+            0x13 => CertPositive,
+            0x18 => SubkeyBinding,
+            0x19 => KeyBinding,
+            0x1f => Key,
+            0x20 => KeyRevocation,
+            0x28 => SubkeyRevocation,
+            0x30 => CertRevocation,
+            0x40 => Timestamp,
+            0x50 => ThirdParty,
+            _ => return None,
+        })
+    }
+}
+
+/// The reason a key or certification was revoked.
+/// Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.23
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum RevocationCode {
+    NoReason,
+    KeySuperseded,
+    KeyCompromised,
+    KeyRetired,
+    CertUserIdInvalid,
+    Other(u8),
+}
+
+impl RevocationCode {
+    pub fn from_u8(n: u8) -> Option<Self> {
+        use RevocationCode::*;
+        Some(match n {
+            0 => NoReason,
+            1 => KeySuperseded,
+            2 => KeyCompromised,
+            3 => KeyRetired,
+            32 => CertUserIdInvalid,
+            n => Other(n),
+        })
+    }
+}
+
+/// A notation, as carried by a `Notation` subpacket.
+/// Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.16
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Notation {
+    pub readable: bool,
+    pub name: String,
+    pub value: String,
+}
+
+/// The type of a subpacket, decoded from the (already demasked) type octet.
+/// Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.1
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SubpacketType {
+    SignatureCreationTime,
+    SignatureExpirationTime,
+    ExportableCertification,
+    TrustSignature,
+    RegularExpression,
+    Revocable,
+    KeyExpirationTime,
+    PreferredSymmetricAlgorithms,
+    RevocationKey,
+    Issuer,
+    Notation,
+    PreferredHashAlgorithms,
+    PreferredCompressionAlgorithms,
+    KeyServerPreferences,
+    PreferredKeyServer,
+    PrimaryUserId,
+    PolicyURI,
+    KeyFlags,
+    SignersUserID,
+    RevocationReason,
+    Features,
+    SignatureTarget,
+    EmbeddedSignature,
+    IssuerFingerprint,
+    PreferredAead,
+    Experimental(u8),
+    Other(u8),
+}
+
+impl SubpacketType {
+    /// Decodes a (demasked) subpacket type octet. Every octet maps to some variant -- an
+    /// unrecognized one simply becomes `Experimental`/`Other` -- so this is total, not fallible.
+    pub fn from_u8(n: u8) -> Self {
+        use SubpacketType::*;
+        match n {
+            2 => SignatureCreationTime,
+            3 => SignatureExpirationTime,
+            4 => ExportableCertification,
+            5 => TrustSignature,
+            6 => RegularExpression,
+            7 => Revocable,
+            9 => KeyExpirationTime,
+            11 => PreferredSymmetricAlgorithms,
+            12 => RevocationKey,
+            16 => Issuer,
+            20 => Notation,
+            21 => PreferredHashAlgorithms,
+            22 => PreferredCompressionAlgorithms,
+            23 => KeyServerPreferences,
+            24 => PreferredKeyServer,
+            25 => PrimaryUserId,
+            26 => PolicyURI,
+            27 => KeyFlags,
+            28 => SignersUserID,
+            29 => RevocationReason,
+            30 => Features,
+            31 => SignatureTarget,
+            32 => EmbeddedSignature,
+            33 => IssuerFingerprint,
+            39 => PreferredAead,
+            100..=110 => Experimental(n),
+            n => Other(n),
+        }
+    }
+
+    /// The raw type octet this variant was (or would be) parsed from, without the critical bit.
+    pub fn to_u8(self) -> u8 {
+        use SubpacketType::*;
+        match self {
+            SignatureCreationTime => 2,
+            SignatureExpirationTime => 3,
+            ExportableCertification => 4,
+            TrustSignature => 5,
+            RegularExpression => 6,
+            Revocable => 7,
+            KeyExpirationTime => 9,
+            PreferredSymmetricAlgorithms => 11,
+            RevocationKey => 12,
+            Issuer => 16,
+            Notation => 20,
+            PreferredHashAlgorithms => 21,
+            PreferredCompressionAlgorithms => 22,
+            KeyServerPreferences => 23,
+            PreferredKeyServer => 24,
+            PrimaryUserId => 25,
+            PolicyURI => 26,
+            KeyFlags => 27,
+            SignersUserID => 28,
+            RevocationReason => 29,
+            Features => 30,
+            SignatureTarget => 31,
+            EmbeddedSignature => 32,
+            IssuerFingerprint => 33,
+            PreferredAead => 39,
+            Experimental(n) | Other(n) => n,
+        }
+    }
+}
+
+/// The decoded contents of a signature subpacket.
+/// Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2.3.1
+#[derive(Debug, PartialEq, Clone)]
+pub enum SubpacketData {
+    SignatureCreationTime(DateTime<Utc>),
+    SignatureExpirationTime(DateTime<Utc>),
+    ExportableCertification(bool),
+    TrustSignature(u8, u8),
+    RegularExpression(String),
+    Revocable(bool),
+    KeyExpirationTime(DateTime<Utc>),
+    PreferredSymmetricAlgorithms(SmallVec<[SymmetricKeyAlgorithm; 8]>),
+    RevocationKey(RevocationKey),
+    Issuer(KeyId),
+    Notation(Notation),
+    PreferredHashAlgorithms(SmallVec<[HashAlgorithm; 8]>),
+    PreferredCompressionAlgorithms(SmallVec<[CompressionAlgorithm; 8]>),
+    KeyServerPreferences(KeyServerPreferences),
+    PreferredKeyServer(String),
+    IsPrimary(bool),
+    PolicyURI(String),
+    KeyFlags(KeyFlags),
+    SignersUserID(String),
+    RevocationReason(RevocationCode, String),
+    Features(Features),
+    SignatureTarget(PublicKeyAlgorithm, HashAlgorithm, Vec<u8>),
+    EmbeddedSignature(Box<Signature>),
+    IssuerFingerprint(KeyVersion, SmallVec<[u8; 20]>),
+    PreferredAeadAlgorithms(SmallVec<[AeadAlgorithm; 2]>),
+    Experimental(u8, SmallVec<[u8; 2]>),
+    Other(u8, Vec<u8>),
+}
+
+/// A signature subpacket, together with the critical bit from its type octet.
+///
+/// Per RFC4880 §5.2.3.1, a receiver that does not understand a subpacket marked critical MUST
+/// treat the whole signature as invalid; see `critical` on this struct and
+/// `reject_unknown_critical` in `de.rs`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Subpacket {
+    pub critical: bool,
+    pub data: SubpacketData,
+}
+
+impl Subpacket {
+    pub fn new(data: SubpacketData, critical: bool) -> Self {
+        Subpacket { critical, data }
+    }
+}
+
+/// Bitfield macro for the small, byte-backed subpacket flag types below: each one wraps its raw
+/// octets verbatim (so unknown/future bits and any trailing octets round-trip byte-exact) and
+/// exposes named accessors and setters over the bits this crate understands.
+macro_rules! bitfield_subpacket {
+    ($name:ident { $( $flag:ident, $set_flag:ident => ($byte:expr, $mask:expr) ),* $(,)? }) => {
+        #[derive(Debug, PartialEq, Eq, Clone, Default)]
+        pub struct $name(SmallVec<[u8; 1]>);
+
+        impl $name {
+            /// Wraps the raw subpacket body, preserving it byte-exact (including any trailing
+            /// octets this implementation doesn't otherwise interpret).
+            pub fn from_slice(raw: &[u8]) -> Self {
+                $name(SmallVec::from_slice(raw))
+            }
+
+            /// The raw octets, exactly as parsed (or as will be serialized).
+            pub fn as_slice(&self) -> &[u8] {
+                &self.0
+            }
+
+            fn bit(&self, byte: usize, mask: u8) -> bool {
+                self.0.get(byte).map_or(false, |b| b & mask != 0)
+            }
+
+            fn set_bit(&mut self, byte: usize, mask: u8, value: bool) -> &mut Self {
+                if self.0.len() <= byte {
+                    self.0.resize(byte + 1, 0);
+                }
+                if value {
+                    self.0[byte] |= mask;
+                } else {
+                    self.0[byte] &= !mask;
+                }
+                self
+            }
+
+            $(
+                pub fn $flag(&self) -> bool {
+                    self.bit($byte, $mask)
+                }
+
+                pub fn $set_flag(&mut self, value: bool) -> &mut Self {
+                    self.set_bit($byte, $mask, value)
+                }
+            )*
+        }
+    };
+}
+
+bitfield_subpacket!(KeyFlags {
+    certify, set_certify => (0, 0x01),
+    sign_data, set_sign_data => (0, 0x02),
+    encrypt_communications, set_encrypt_communications => (0, 0x04),
+    encrypt_storage, set_encrypt_storage => (0, 0x08),
+    split_key, set_split_key => (0, 0x10),
+    authentication, set_authentication => (0, 0x20),
+    shared_key, set_shared_key => (0, 0x80),
+});
+
+bitfield_subpacket!(Features {
+    seipd_v1, set_seipd_v1 => (0, 0x01),
+    aead, set_aead => (0, 0x02),
+});
+
+bitfield_subpacket!(KeyServerPreferences {
+    no_modify, set_no_modify => (0, 0x80),
+});
+
+/// Fields only set on some signature versions: the issuer/created time carried directly by a
+/// V3 signature instead of as subpackets, or the salt a V5 signature hashes alongside the
+/// signed data (a field V4 signatures don't have).
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct SignatureConfig {
+    pub created: Option<DateTime<Utc>>,
+    pub issuer: Option<KeyId>,
+    pub salt: Option<Vec<u8>>,
+}
+
+/// A Signature packet (Tag 2).
+/// Ref: https://tools.ietf.org/html/rfc4880.html#section-5.2
+#[derive(Debug, PartialEq, Clone)]
+pub struct Signature {
+    pub packet_version: Version,
+    pub version: SignatureVersion,
+    pub typ: SignatureType,
+    pub pub_alg: PublicKeyAlgorithm,
+    pub hash_alg: HashAlgorithm,
+    pub signed_hash_value: [u8; 2],
+    pub signature: Vec<Mpi>,
+    pub hashed_subpackets: Vec<Subpacket>,
+    pub unhashed_subpackets: Vec<Subpacket>,
+    pub config: SignatureConfig,
+}
+
+impl Signature {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        packet_version: Version,
+        version: SignatureVersion,
+        typ: SignatureType,
+        pub_alg: PublicKeyAlgorithm,
+        hash_alg: HashAlgorithm,
+        signed_hash_value: [u8; 2],
+        signature: Vec<Mpi>,
+        hashed_subpackets: Vec<Subpacket>,
+        unhashed_subpackets: Vec<Subpacket>,
+    ) -> Self {
+        Signature {
+            packet_version,
+            version,
+            typ,
+            pub_alg,
+            hash_alg,
+            signed_hash_value,
+            signature,
+            hashed_subpackets,
+            unhashed_subpackets,
+            config: SignatureConfig::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_flags_accessors() {
+        let flags = KeyFlags::from_slice(&[0x01 | 0x02 | 0x80]);
+        assert!(flags.certify());
+        assert!(flags.sign_data());
+        assert!(flags.shared_key());
+        assert!(!flags.encrypt_communications());
+        assert!(!flags.split_key());
+    }
+
+    #[test]
+    fn test_key_flags_setters_round_trip() {
+        let mut flags = KeyFlags::default();
+        flags.set_encrypt_storage(true).set_authentication(true);
+        assert!(flags.encrypt_storage());
+        assert!(flags.authentication());
+        assert_eq!(flags.as_slice(), &[0x08 | 0x20]);
+    }
+
+    #[test]
+    fn test_key_flags_preserves_unknown_trailing_octets() {
+        // Octet 0 plus an unknown future octet 1: round-tripping must not drop it.
+        let flags = KeyFlags::from_slice(&[0x01, 0xff]);
+        assert!(flags.certify());
+        assert_eq!(flags.as_slice(), &[0x01, 0xff]);
+    }
+
+    #[test]
+    fn test_features_accessors() {
+        let features = Features::from_slice(&[0x01]);
+        assert!(features.seipd_v1());
+        assert!(!features.aead());
+    }
+
+    #[test]
+    fn test_key_server_preferences_accessors() {
+        let prefs = KeyServerPreferences::from_slice(&[0x80]);
+        assert!(prefs.no_modify());
+
+        let mut prefs = KeyServerPreferences::default();
+        assert!(!prefs.no_modify());
+        prefs.set_no_modify(true);
+        assert!(prefs.no_modify());
+    }
+}